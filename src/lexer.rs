@@ -0,0 +1,179 @@
+//! Tokenizer for the semver grammar.
+//!
+//! The lexer walks the input once, character by character, and hands the parser a stream of
+//! [`Token`](enum.Token.html)s. It does not know anything about the grammar itself (e.g. it
+//! doesn't care whether a `.` is valid where it appears) — that's the parser's job.
+
+use std::str::CharIndices;
+
+/// An error produced while lexing, carrying the offending character and its byte offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// Encountered a character that isn't part of any token.
+    UnexpectedChar(char, usize),
+}
+
+/// A single lexical token.
+///
+/// `Numeric` carries the literal byte width of the digits it was lexed from (in addition to
+/// their parsed value), so that callers needing the token's source span don't have to
+/// reconstruct it by re-formatting the number — that reconstruction silently disagrees with the
+/// real span for inputs like a leading-zero run (`007`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Token<'input> {
+    /// Runs of whitespace, together with their start and end byte offsets.
+    Whitespace(usize, usize),
+    /// A run of ASCII digits, together with its value and literal byte width.
+    Numeric(u64, usize),
+    /// A run of alphanumeric characters, e.g. a pre-release or build identifier. A `-` is not
+    /// part of the run; it is lexed as a separate `Hyphen` token.
+    AlphaNumeric(&'input str),
+    /// `.`
+    Dot,
+    /// `,`
+    Comma,
+    /// `-`
+    Hyphen,
+    /// `+`
+    Plus,
+    /// `||`
+    Or,
+    /// `=`
+    Eq,
+    /// `>`
+    Gt,
+    /// `<`
+    Lt,
+    /// `>=`
+    GtEq,
+    /// `<=`
+    LtEq,
+    /// `~`
+    Tilde,
+    /// `^`
+    Caret,
+    /// `*`
+    Star,
+}
+
+impl<'input> Token<'input> {
+    /// Whether this token stands for a wildcard component (`*`, `x`, or `X`).
+    pub fn is_wildcard(&self) -> bool {
+        match *self {
+            Token::Star => true,
+            Token::AlphaNumeric(rest) => rest == "x" || rest == "X",
+            _ => false,
+        }
+    }
+}
+
+/// Lexer over a semver range or version string.
+pub struct Lexer<'input> {
+    input: &'input str,
+    chars: CharIndices<'input>,
+    c1: Option<(usize, char)>,
+}
+
+impl<'input> Lexer<'input> {
+    /// Construct a new lexer over `input`.
+    pub fn new(input: &'input str) -> Self {
+        let mut chars = input.char_indices();
+        let c1 = chars.next();
+
+        Lexer {
+            input: input,
+            chars: chars,
+            c1: c1,
+        }
+    }
+
+    /// Advance past the current lookahead character, returning it.
+    fn bump(&mut self) -> Option<(usize, char)> {
+        let cur = self.c1;
+        self.c1 = self.chars.next();
+        cur
+    }
+
+    /// Consume characters matching `is_member` starting at `end` (the byte offset just past the
+    /// character that triggered this run), returning the end offset of the run and the full
+    /// slice from `start`.
+    fn take_while<F>(&mut self, start: usize, mut end: usize, mut is_member: F) -> (usize, &'input str)
+        where F: FnMut(char) -> bool
+    {
+        loop {
+            match self.c1 {
+                Some((offset, c)) if is_member(c) => {
+                    end = offset + c.len_utf8();
+                    self.bump();
+                }
+                _ => break,
+            }
+        }
+
+        (end, &self.input[start..end])
+    }
+}
+
+impl<'input> Iterator for Lexer<'input> {
+    type Item = Result<Token<'input>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, c) = match self.bump() {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let token = match c {
+            '.' => Token::Dot,
+            ',' => Token::Comma,
+            '-' => Token::Hyphen,
+            '+' => Token::Plus,
+            '=' => Token::Eq,
+            '^' => Token::Caret,
+            '~' => Token::Tilde,
+            '*' => Token::Star,
+            '|' => {
+                if let Some((_, '|')) = self.c1 {
+                    self.bump();
+                }
+
+                Token::Or
+            }
+            '>' => {
+                if let Some((_, '=')) = self.c1 {
+                    self.bump();
+                    Token::GtEq
+                } else {
+                    Token::Gt
+                }
+            }
+            '<' => {
+                if let Some((_, '=')) = self.c1 {
+                    self.bump();
+                    Token::LtEq
+                } else {
+                    Token::Lt
+                }
+            }
+            c if c.is_whitespace() => {
+                let (end, _) = self.take_while(start, start + c.len_utf8(), |c| c.is_whitespace());
+                Token::Whitespace(start, end)
+            }
+            c if c.is_ascii_digit() => {
+                let (end, digits) = self.take_while(start, start + c.len_utf8(), |c| c.is_ascii_digit());
+
+                match digits.parse::<u64>() {
+                    Ok(number) => Token::Numeric(number, end - start),
+                    Err(_) => return Some(Err(Error::UnexpectedChar(c, start))),
+                }
+            }
+            c if c.is_alphanumeric() => {
+                let (_, rest) = self.take_while(start, start + c.len_utf8(), |c| c.is_alphanumeric());
+                Token::AlphaNumeric(rest)
+            }
+            c => return Some(Err(Error::UnexpectedChar(c, start))),
+        };
+
+        Some(Ok(token))
+    }
+}
@@ -29,6 +29,7 @@
 //!     minor: Some(0),
 //!     patch: None,
 //!     pre: vec![],
+//!     build: vec![],
 //! })), p.predicate());
 //!
 //! let mut p = Parser::new("^*").expect("a broken parser");
@@ -43,6 +44,22 @@ use comparator::Comparator;
 use version::{Version, Identifier};
 use std::mem;
 use std::fmt;
+use std::ops::Range;
+
+/// The byte width of a token, used to report the span of an offending token in parse errors.
+///
+/// `Numeric` and `Whitespace` carry their literal lexed width directly (threaded from the
+/// lexer), so this never has to reconstruct it; the remaining variants are fixed-width sigils
+/// whose width is constant regardless of the source text.
+fn token_width(token: &Token) -> usize {
+    match *token {
+        Token::Numeric(_, width) => width,
+        Token::AlphaNumeric(rest) => rest.len(),
+        Token::Whitespace(start, end) => end - start,
+        Token::GtEq | Token::LtEq | Token::Or => 2,
+        _ => 1,
+    }
+}
 
 /// Evaluate if parser contains the given pattern as a separator, surrounded by whitespace.
 macro_rules! has_ws_separator {
@@ -62,12 +79,46 @@ macro_rules! has_ws_separator {
     }}
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+/// Indicates which part of a version or version requirement the parser was reading when an
+/// error occurred.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Position {
+    /// Parsing the major version component.
+    Major,
+    /// Parsing the minor version component.
+    Minor,
+    /// Parsing the patch version component.
+    Patch,
+    /// Parsing pre-release identifiers.
+    Pre,
+    /// Parsing build metadata.
+    Build,
+    /// Parsing a comparison operator.
+    Op,
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Position::Major => write!(fmt, "major version number"),
+            Position::Minor => write!(fmt, "minor version number"),
+            Position::Patch => write!(fmt, "patch version number"),
+            Position::Pre => write!(fmt, "pre-release identifier"),
+            Position::Build => write!(fmt, "build metadata"),
+            Position::Op => write!(fmt, "operator"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
 pub enum Error<'input> {
-    /// Needed more tokens for parsing, but none are available.
-    UnexpectedEnd,
-    /// Unexpected token.
-    UnexpectedToken(Token<'input>),
+    /// Needed more tokens for parsing, but none are available. Carries the byte offset of the
+    /// end of input and an optional machine-applicable suggestion for fixing the input.
+    UnexpectedEnd(Position, usize, Option<(Range<usize>, String)>),
+    /// Unexpected token, together with the byte span it occupies, what the parser was trying
+    /// to read when it encountered it, and an optional machine-applicable suggestion for
+    /// fixing the input.
+    UnexpectedToken(Token<'input>, Range<usize>, Position, Option<(Range<usize>, String)>),
     /// An error occurred in the lexer.
     Lexer(lexer::Error),
     /// More input available.
@@ -76,6 +127,9 @@ pub enum Error<'input> {
     EmptyPredicate,
     /// Encountered an empty range.
     EmptyRange,
+    /// A concrete component, pre-release, or build metadata followed an earlier wildcard
+    /// component, e.g. `1.*.0` or `1.*-beta`.
+    IllegalWildcard { span: Range<usize>, position: Position },
 }
 
 impl<'input> From<lexer::Error> for Error<'input> {
@@ -89,12 +143,31 @@ impl<'input> fmt::Display for Error<'input> {
         use self::Error::*;
 
         match *self {
-            UnexpectedEnd => write!(fmt, "expected more input"),
-            UnexpectedToken(ref token) => write!(fmt, "encountered unexpected token: {:?}", token),
+            UnexpectedEnd(position, offset, ref suggestion) => {
+                write!(fmt, "expected more input while parsing {} at byte {}", position, offset)?;
+
+                if let Some((_, ref hint)) = *suggestion {
+                    write!(fmt, " (hint: {})", hint)?;
+                }
+
+                Ok(())
+            }
+            UnexpectedToken(ref token, ref span, position, ref suggestion) => {
+                write!(fmt, "unexpected character `{:?}` while parsing {} at byte {}", token, position, span.start)?;
+
+                if let Some((_, ref hint)) = *suggestion {
+                    write!(fmt, " (hint: {})", hint)?;
+                }
+
+                Ok(())
+            }
             Lexer(ref error) => write!(fmt, "lexer error: {:?}", error),
             MoreInput(ref tokens) => write!(fmt, "expected end of input, but got: {:?}", tokens),
             EmptyPredicate => write!(fmt, "encountered empty predicate"),
             EmptyRange => write!(fmt, "encountered empty range"),
+            IllegalWildcard { ref span, position } => {
+                write!(fmt, "illegal wildcard combination while parsing {} at byte {}", position, span.start)
+            }
         }
     }
 }
@@ -112,6 +185,14 @@ pub struct Parser<'input> {
     lexer: Lexer<'input>,
     /// Lookaehead.
     c1: Option<Token<'input>>,
+    /// Second lookahead token, used to detect bogus two-character sigil sequences like `=>`.
+    c2: Option<Token<'input>>,
+    /// Byte offset of the current lookahead token within the input.
+    offset: usize,
+    /// Which part of the version or version requirement is currently being parsed.
+    position: Position,
+    /// Fix-it suggestions collected while parsing, keyed by the byte range they apply to.
+    diagnostics: Vec<(Range<usize>, String)>,
 }
 
 impl<'input> Parser<'input> {
@@ -125,30 +206,97 @@ impl<'input> Parser<'input> {
             None
         };
 
+        let c2 = if let Some(c2) = lexer.next() {
+            Some(c2?)
+        } else {
+            None
+        };
+
         Ok(Parser {
             lexer: lexer,
             c1: c1,
+            c2: c2,
+            offset: 0,
+            position: Position::Major,
+            diagnostics: Vec::new(),
         })
     }
 
     /// Pop one token.
     #[inline(always)]
     fn pop(&mut self) -> Result<Token<'input>, Error<'input>> {
-        let c1 = if let Some(c1) = self.lexer.next() {
-            Some(c1?)
+        if let Some(ref tok) = self.c1 {
+            self.offset += token_width(tok);
+        }
+
+        let next = if let Some(next) = self.lexer.next() {
+            Some(next?)
         } else {
             None
         };
 
-        mem::replace(&mut self.c1, c1).ok_or_else(|| UnexpectedEnd)
+        let position = self.position;
+        let offset = self.offset;
+        let c1 = mem::replace(&mut self.c1, self.c2.take());
+        self.c2 = next;
+        c1.ok_or_else(|| UnexpectedEnd(position, offset, None))
+    }
+
+    /// Build an `UnexpectedToken` error for `token`, tagged with the byte span it occupies and
+    /// the part of the grammar we were parsing when we encountered it.
+    ///
+    /// `start` must be the offset the token was popped from (i.e. `self.offset` as observed
+    /// *before* the `pop()` that returned it), since popping already advances `self.offset` to
+    /// the start of the following token.
+    fn unexpected_token(&self, token: Token<'input>, start: usize) -> Error<'input> {
+        let end = start + token_width(&token);
+        UnexpectedToken(token, start..end, self.position, None)
+    }
+
+    /// Like [`unexpected_token`](#method.unexpected_token), but records a machine-applicable
+    /// suggestion for fixing the input alongside the error.
+    fn unexpected_token_with_suggestion(&mut self, token: Token<'input>, span: Range<usize>, message: String) -> Error<'input> {
+        self.diagnostics.push((span.clone(), message.clone()));
+        UnexpectedToken(token, span.clone(), self.position, Some((span, message)))
+    }
+
+    /// Fix-it suggestions collected while parsing so far, keyed by the byte range they apply
+    /// to. Editors can use these to offer quick-fixes without re-deriving them from the error.
+    pub fn diagnostics(&self) -> &[(Range<usize>, String)] {
+        &self.diagnostics
+    }
+
+    /// Annotate an error from a failed major-component parse with a suggestion noting that a
+    /// major version number is required after the operator.
+    fn require_major(&mut self, error: Error<'input>) -> Error<'input> {
+        match error {
+            UnexpectedToken(token, span, position, _) => {
+                let message = "a major version number is required here".to_string();
+                self.diagnostics.push((span.clone(), message.clone()));
+                UnexpectedToken(token, span.clone(), position, Some((span, message)))
+            }
+            UnexpectedEnd(position, offset, _) => {
+                let message = "a major version number is required here".to_string();
+                let span = offset..offset;
+                self.diagnostics.push((span.clone(), message.clone()));
+                UnexpectedEnd(position, offset, Some((span, message)))
+            }
+            other => other,
+        }
     }
 
     /// Peek one token.
     #[inline(always)]
-    fn peek(&mut self) -> Option<&Token<'input>> {
+    fn peek(&self) -> Option<&Token<'input>> {
         self.c1.as_ref()
     }
 
+    /// Peek the token after the current lookahead.
+    #[inline(always)]
+    fn peek2(&self) -> Option<&Token<'input>> {
+        self.c2.as_ref()
+    }
+
     /// Skip whitespace if present.
     fn skip_whitespace(&mut self) -> Result<(), Error<'input>> {
         match self.peek() {
@@ -183,18 +331,22 @@ impl<'input> Parser<'input> {
     ///
     /// Returns `None` if the component is a wildcard.
     pub fn component(&mut self) -> Result<Option<u64>, Error<'input>> {
+        let start = self.offset;
+
         match self.pop()? {
-            Token::Numeric(number) => Ok(Some(number)),
+            Token::Numeric(number, _) => Ok(Some(number)),
             ref t if t.is_wildcard() => Ok(None),
-            tok => Err(UnexpectedToken(tok)),
+            tok => Err(self.unexpected_token(tok, start)),
         }
     }
 
     /// Parse a single numeric.
     pub fn numeric(&mut self) -> Result<u64, Error<'input>> {
+        let start = self.offset;
+
         match self.pop()? {
-            Token::Numeric(number) => Ok(number),
-            tok => Err(UnexpectedToken(tok)),
+            Token::Numeric(number, _) => Ok(number),
+            tok => Err(self.unexpected_token(tok, start)),
         }
     }
 
@@ -219,9 +371,11 @@ impl<'input> Parser<'input> {
 
     /// Parse a dot, then a numeric.
     pub fn dot_numeric(&mut self) -> Result<u64, Error<'input>> {
+        let start = self.offset;
+
         match self.pop()? {
             Token::Dot => {}
-            tok => return Err(UnexpectedToken(tok)),
+            tok => return Err(self.unexpected_token(tok, start)),
         }
 
         self.numeric()
@@ -231,13 +385,15 @@ impl<'input> Parser<'input> {
     ///
     /// Like, `foo`, or `bar`.
     pub fn identifier(&mut self) -> Result<Identifier, Error<'input>> {
+        let start = self.offset;
+
         let identifier = match self.pop()? {
             Token::AlphaNumeric(identifier) => {
                 // TODO: Borrow?
                 Identifier::AlphaNumeric(identifier.to_string())
             }
-            Token::Numeric(n) => Identifier::Numeric(n),
-            tok => return Err(UnexpectedToken(tok)),
+            Token::Numeric(n, _) => Identifier::Numeric(n),
+            tok => return Err(self.unexpected_token(tok, start)),
         };
 
         Ok(identifier)
@@ -298,6 +454,12 @@ impl<'input> Parser<'input> {
     pub fn op(&mut self) -> Result<Op, Error<'input>> {
         use self::Token::*;
 
+        self.position = Position::Op;
+
+        if let Some(error) = self.bogus_sigil()? {
+            return Err(error);
+        }
+
         let op = match self.peek() {
             Some(&Eq) => Op::Ex,
             Some(&Gt) => Op::Gt,
@@ -316,6 +478,28 @@ impl<'input> Parser<'input> {
         Ok(op)
     }
 
+    /// Detect a reversed two-character sigil sequence (`=>`, `=<`, `->`) that was likely meant
+    /// to be `>=` or `<=`, and turn it into a suggestion-carrying error instead of letting it
+    /// fall through to a confusing failure further down the grammar.
+    fn bogus_sigil(&mut self) -> Result<Option<Error<'input>>, Error<'input>> {
+        use self::Token::*;
+
+        let suggestion = match (self.peek(), self.peek2()) {
+            (Some(&Eq), Some(&Gt)) => ">=",
+            (Some(&Eq), Some(&Lt)) => "<=",
+            (Some(&Hyphen), Some(&Gt)) => ">=",
+            _ => return Ok(None),
+        };
+
+        let start = self.offset;
+        let first = self.pop()?;
+        let second = self.pop()?;
+        let end = start + token_width(&first) + token_width(&second);
+        let message = format!("did you mean `{}`?", suggestion);
+
+        Ok(Some(self.unexpected_token_with_suggestion(second, start..end, message)))
+    }
+
     /// Parse a single predicate.
     ///
     /// Like, `^1`, or `>=2.0.0`.
@@ -327,16 +511,52 @@ impl<'input> Parser<'input> {
 
         let mut op = self.op()?;
 
-        let major = match self.component()? {
-            Some(major) => major,
-            None => return Ok(None),
+        self.position = Position::Major;
+        let major = match self.component() {
+            Ok(Some(major)) => major,
+            Ok(None) => {
+                // a concrete minor/patch may not follow a wildcard major, e.g. `*.1.0`.
+                if let Some(&Token::Dot) = self.peek() {
+                    let span_start = self.offset;
+
+                    return Err(IllegalWildcard {
+                        span: span_start..span_start,
+                        position: Position::Minor,
+                    });
+                }
+
+                return Ok(None);
+            }
+            Err(error) => return Err(self.require_major(error)),
         };
 
+        self.position = Position::Minor;
         let (minor, minor_wildcard) = self.dot_component()?;
+
+        self.position = Position::Patch;
+        let patch_start = self.offset;
         let (patch, patch_wildcard) = self.dot_component()?;
+
+        // a concrete patch may not follow a wildcard minor, e.g. `1.*.0`.
+        if minor_wildcard && patch.is_some() {
+            return Err(IllegalWildcard {
+                span: patch_start..self.offset,
+                position: Position::Patch,
+            });
+        }
+
+        self.position = Position::Pre;
+        let pre_start = self.offset;
         let pre = self.pre()?;
 
-        // TODO: avoid illegal combinations, like `1.*.0`.
+        // a wildcard predicate may not carry a pre-release, e.g. `1.*-beta`.
+        if (minor_wildcard || patch_wildcard) && !pre.is_empty() {
+            return Err(IllegalWildcard {
+                span: pre_start..self.offset,
+                position: Position::Pre,
+            });
+        }
+
         if minor_wildcard {
             op = Op::Wildcard(WildcardVersion::Minor);
         }
@@ -345,8 +565,17 @@ impl<'input> Parser<'input> {
             op = Op::Wildcard(WildcardVersion::Patch);
         }
 
-        // ignore build metadata
-        self.plus_build_metadata()?;
+        self.position = Position::Build;
+        let build_start = self.offset;
+        let build = self.plus_build_metadata()?;
+
+        // a wildcard predicate may not carry build metadata, e.g. `1.*+build`.
+        if (minor_wildcard || patch_wildcard) && !build.is_empty() {
+            return Err(IllegalWildcard {
+                span: build_start..self.offset,
+                position: Position::Build,
+            });
+        }
 
         Ok(Some(Predicate {
             op: op,
@@ -354,12 +583,18 @@ impl<'input> Parser<'input> {
             minor: minor,
             patch: patch,
             pre: pre,
+            build: build,
         }))
     }
 
     /// Parse a single range.
     ///
     /// Like, `^1.0` or `>=3.0.0, <4.0.0`.
+    ///
+    /// Fails on the first error encountered, leaving the parser positioned right after the
+    /// offending token, same as before error recovery was added. Use
+    /// [`range_recovering`](#method.range_recovering) to collect every error in the input
+    /// instead, at the cost of the parser consuming further into the input on error.
     pub fn range(&mut self) -> Result<VersionReq, Error<'input>> {
         let mut predicates = Vec::new();
 
@@ -374,6 +609,75 @@ impl<'input> Parser<'input> {
         Ok(VersionReq { predicates: predicates })
     }
 
+    /// Parse a single range, collecting every error encountered instead of stopping at the
+    /// first one.
+    ///
+    /// On an `UnexpectedToken`, the parser records the error and skips tokens up to the next
+    /// synchronization point (a `Comma`, `Or`, or end of input), then keeps going so that as
+    /// much of the `VersionReq` as possible is still built.
+    pub fn range_recovering(&mut self) -> (VersionReq, Vec<Error<'input>>) {
+        let mut predicates = Vec::new();
+        let mut errors = Vec::new();
+
+        match self.predicate() {
+            Ok(Some(predicate)) => predicates.push(predicate),
+            Ok(None) => {}
+            Err(error) => {
+                errors.push(error);
+                self.recover_to_range_sync();
+            }
+        }
+
+        loop {
+            let _ = self.skip_whitespace();
+
+            match self.peek() {
+                Some(&Token::Comma) => {
+                    let _ = self.pop();
+                }
+                _ => break,
+            }
+
+            let _ = self.skip_whitespace();
+
+            match self.predicate() {
+                Ok(Some(predicate)) => predicates.push(predicate),
+                Ok(None) => errors.push(EmptyPredicate),
+                Err(error) => {
+                    errors.push(error);
+                    self.recover_to_range_sync();
+                }
+            }
+        }
+
+        (VersionReq { predicates: predicates }, errors)
+    }
+
+    /// Skip tokens until a `Comma`, `Or`, or end of input is reached.
+    fn recover_to_range_sync(&mut self) {
+        self.recover_until(|tok| match *tok {
+            Token::Comma | Token::Or => true,
+            _ => false,
+        });
+    }
+
+    /// Skip tokens until `is_sync` matches the next token, or input runs out.
+    fn recover_until<F>(&mut self, is_sync: F)
+        where F: Fn(&Token<'input>) -> bool
+    {
+        loop {
+            match self.peek() {
+                Some(tok) if is_sync(tok) => return,
+                None => return,
+                _ => {}
+            }
+
+            if self.pop().is_err() {
+                return;
+            }
+        }
+    }
+
     /// Parse a comparator.
     ///
     /// Like, `1.0 || 2.0` or `^1 || >=3.0.0, <4.0.0`.
@@ -391,13 +695,23 @@ impl<'input> Parser<'input> {
     /// Parse a version.
     ///
     /// Like, `1.0.0` or `3.0.0-beta.1`.
+    ///
+    /// Fails on the first error encountered, leaving the parser positioned right after the
+    /// offending token, same as before error recovery was added. Use
+    /// [`version_recovering`](#method.version_recovering) to collect every error in the input
+    /// instead, at the cost of the parser consuming further into the input on error.
     pub fn version(&mut self) -> Result<Version, Error<'input>> {
         self.skip_whitespace()?;
 
+        self.position = Position::Major;
         let major = self.numeric()?;
+        self.position = Position::Minor;
         let minor = self.dot_numeric()?;
+        self.position = Position::Patch;
         let patch = self.dot_numeric()?;
+        self.position = Position::Pre;
         let pre = self.pre()?;
+        self.position = Position::Build;
         let build = self.plus_build_metadata()?;
 
         self.skip_whitespace()?;
@@ -411,6 +725,66 @@ impl<'input> Parser<'input> {
         })
     }
 
+    /// Parse a version, collecting every error encountered instead of stopping at the first
+    /// one.
+    ///
+    /// On an error, the parser records it, substitutes a placeholder for the component it
+    /// failed to read, and goes on with the next component. Once build metadata has been read,
+    /// if any error was recorded, it skips tokens up to the next whitespace boundary or end of
+    /// input.
+    pub fn version_recovering(&mut self) -> (Version, Vec<Error<'input>>) {
+        let mut errors = Vec::new();
+
+        let _ = self.skip_whitespace();
+
+        self.position = Position::Major;
+        let major = self.numeric().unwrap_or_else(|error| {
+            errors.push(error);
+            0
+        });
+
+        self.position = Position::Minor;
+        let minor = self.dot_numeric().unwrap_or_else(|error| {
+            errors.push(error);
+            0
+        });
+
+        self.position = Position::Patch;
+        let patch = self.dot_numeric().unwrap_or_else(|error| {
+            errors.push(error);
+            0
+        });
+
+        self.position = Position::Pre;
+        let pre = self.pre().unwrap_or_else(|error| {
+            errors.push(error);
+            vec![]
+        });
+
+        self.position = Position::Build;
+        let build = self.plus_build_metadata().unwrap_or_else(|error| {
+            errors.push(error);
+            vec![]
+        });
+
+        if !errors.is_empty() {
+            self.recover_until(|tok| match *tok {
+                Token::Whitespace(_, _) => true,
+                _ => false,
+            });
+        }
+
+        let _ = self.skip_whitespace();
+
+        (Version {
+            major: major,
+            minor: minor,
+            patch: patch,
+            pre: pre,
+            build: build,
+        }, errors)
+    }
+
     /// Check if we have reached the end of input.
     pub fn is_eof(&mut self) -> bool {
         self.c1.is_none()
@@ -426,6 +800,10 @@ impl<'input> Parser<'input> {
             out.push(t);
         }
 
+        if let Some(t) = self.c2.take() {
+            out.push(t);
+        }
+
         while let Some(t) = self.lexer.next() {
             out.push(t?);
         }
@@ -433,3 +811,101 @@ impl<'input> Parser<'input> {
         Ok(out)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unexpected_token_reports_position_and_byte_span() {
+        let mut p = Parser::new("1.q.0").unwrap();
+
+        match p.version() {
+            Err(UnexpectedToken(_, span, Position::Minor, _)) => assert_eq!(2..3, span),
+            other => panic!("expected a minor-version UnexpectedToken error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn unexpected_end_reports_position_and_byte_offset() {
+        let mut p = Parser::new("1.0.0-").unwrap();
+
+        match p.version() {
+            Err(UnexpectedEnd(Position::Pre, offset, _)) => assert_eq!(6, offset),
+            other => panic!("expected a pre-release UnexpectedEnd error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn predicate_build_metadata_is_populated() {
+        let mut p = Parser::new("^1.0.0+build.5").unwrap();
+        let predicate = p.predicate().unwrap().unwrap();
+
+        assert_eq!(vec![Identifier::AlphaNumeric("build".to_string()), Identifier::Numeric(5)], predicate.build);
+    }
+
+    #[test]
+    fn predicate_build_metadata_absent_is_empty() {
+        let mut p = Parser::new("^1.0.0").unwrap();
+        let predicate = p.predicate().unwrap().unwrap();
+
+        assert!(predicate.build.is_empty());
+    }
+
+    #[test]
+    fn wildcard_combinations_truth_table() {
+        let cases = [
+            ("1.*", true),
+            ("1.*.0", false),
+            ("*.1.0", false),
+            ("1.2.*", true),
+        ];
+
+        for &(input, accepted) in cases.iter() {
+            let mut p = Parser::new(input).unwrap();
+            let result = p.predicate();
+            assert_eq!(accepted, result.is_ok(), "input: {} result: {:?}", input, result);
+        }
+    }
+
+    #[test]
+    fn wildcard_major_does_not_consume_past_the_first_bad_token() {
+        let mut p = Parser::new("*.1.0, 2.0.0").unwrap();
+
+        assert!(p.predicate().is_err());
+        // the rejected `.1.0, 2.0.0` tail must still be sitting unconsumed; only the `*` is
+        // popped.
+        assert_eq!(11, p.tail().unwrap().len());
+    }
+
+    #[test]
+    fn operator_with_missing_major_at_eof_suggests_major_required() {
+        let mut p = Parser::new(">=").unwrap();
+
+        match p.predicate() {
+            Err(UnexpectedEnd(Position::Major, _, Some((_, ref hint)))) => {
+                assert_eq!("a major version number is required here", hint);
+            }
+            other => panic!("expected an UnexpectedEnd error with a suggestion, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn version_does_not_consume_past_the_first_bad_token() {
+        let mut p = Parser::new("1.q.0").unwrap();
+
+        assert!(p.version().is_err());
+        // the trailing `.0` after the bad `q` token must still be sitting unconsumed, not
+        // read as the patch the way `version_recovering` would.
+        assert_eq!(2, p.tail().unwrap().len());
+    }
+
+    #[test]
+    fn range_does_not_consume_past_the_first_bad_token() {
+        let mut p = Parser::new("1.0.0, q, 2.0.0").unwrap();
+
+        assert!(p.range().is_err());
+        // everything after the bad `q` token must still be sitting unconsumed.
+        assert_eq!(7, p.tail().unwrap().len());
+    }
+}
@@ -0,0 +1,61 @@
+//! Types describing version requirements (ranges of acceptable versions).
+
+use version::Identifier;
+
+/// The relation between an operator and a version, used by the default and `^` operators to
+/// decide which of a version's components are allowed to vary.
+#[derive(Debug, PartialEq, Eq)]
+pub enum CompatibleOp {
+    /// Given by `^`, allows changes that do not modify the left-most non-zero component.
+    Caret,
+    /// No operator was present; follows the same compatibility rules as `Caret`.
+    Default_,
+}
+
+/// Indicates which component of a predicate was written as a wildcard (`*`, `x`, or `X`).
+#[derive(Debug, PartialEq, Eq)]
+pub enum WildcardVersion {
+    /// The minor component was a wildcard, e.g. `1.*`.
+    Minor,
+    /// The patch component was a wildcard, e.g. `1.2.*`.
+    Patch,
+}
+
+/// A comparison operator together with any additional semantics it carries.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Op {
+    /// `=`
+    Ex,
+    /// `>`
+    Gt,
+    /// `>=`
+    GtEq,
+    /// `<`
+    Lt,
+    /// `<=`
+    LtEq,
+    /// `~`
+    Tilde,
+    /// `^`, or no operator at all.
+    Compatible(CompatibleOp),
+    /// A wildcard was encountered in the component named by the `WildcardVersion`.
+    Wildcard(WildcardVersion),
+}
+
+/// A single version predicate, like `^1.2.3` or `>=2.0.0`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Predicate {
+    pub op: Op,
+    pub major: u64,
+    pub minor: Option<u64>,
+    pub patch: Option<u64>,
+    pub pre: Vec<Identifier>,
+    /// Build metadata, e.g. the `build.5` in `^1.0.0+build.5`. Empty if none was present.
+    pub build: Vec<Identifier>,
+}
+
+/// A set of predicates, all of which must match for a version to satisfy the requirement.
+#[derive(Debug, PartialEq, Eq)]
+pub struct VersionReq {
+    pub predicates: Vec<Predicate>,
+}